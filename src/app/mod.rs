@@ -2,49 +2,83 @@
 
 pub(crate) mod subcommand;
 
-use std::io;
-use structopt::StructOpt;
-use subcommand::Execute;
+pub use subcommand::{Context, Error, ErrorContext, Execute, Result};
+
+use std::io::{self, Write};
+use std::sync::Mutex;
 
 /// A command line utility to simplify logging into AWS accounts and services.
 ///
 /// TBD
-#[derive(StructOpt)]
+#[derive(clap::Parser)]
 pub struct Application {
     /// Use a specific AWS CLI or template profile to use.
-    #[structopt(long)]
+    #[clap(long)]
     profile: Option<String>,
 
     /// Use a specific AWS region, overriding profile and environment settings.
-    #[structopt(long)]
+    #[clap(long)]
     region: Option<String>,
 
     /// AWS account or managed service to log into
-    #[structopt(subcommand)]
+    #[clap(subcommand)]
     subcommand: subcommand::Subcommand,
 }
 
 impl Application {
-    /// Executes the request subcommand.
-    pub fn execute(
-        &self,
-        error: &mut impl io::Write,
-        output: &mut impl io::Write,
-    ) -> subcommand::Result<()> {
-        use subcommand::Subcommand::*;
-
-        match &self.subcommand {
-            Ecr(cmd) => cmd.execute(self, error, output),
+    /// Executes the requested subcommand.
+    pub fn execute(&self) -> Result<()> {
+        let mut context = ApplicationContext::new(self);
+
+        self.subcommand.execute(&mut context)
+    }
+}
+
+/// The [`Context`] implementation used when running as the compiled binary.
+pub struct ApplicationContext<'a> {
+    /// The parsed command line application this context was created from.
+    application: &'a Application,
+
+    /// The stream standard output is written to.
+    output: Mutex<Box<dyn Write + Send>>,
+
+    /// The stream error output is written to.
+    error: Mutex<Box<dyn Write + Send>>,
+}
+
+impl<'a> ApplicationContext<'a> {
+    /// Creates a new instance wrapping the given [`Application`].
+    ///
+    /// ```
+    /// use crate::app;
+    /// use clap::Parser;
+    ///
+    /// let app = app::Application::parse();
+    /// let mut context = app::ApplicationContext::new(&app);
+    /// ```
+    pub fn new(application: &'a Application) -> Self {
+        Self {
+            application,
+            output: Mutex::new(Box::new(io::stdout())),
+            error: Mutex::new(Box::new(io::stderr())),
         }
     }
 }
 
-impl subcommand::Context for Application {
+impl Context for ApplicationContext<'_> {
     fn profile(&self) -> Option<&str> {
-        self.profile.as_deref()
+        self.application.profile.as_deref()
     }
 
     fn region(&self) -> Option<&str> {
-        self.region.as_deref()
+        self.application.region.as_deref()
+    }
+
+    fn output(&self) -> &Mutex<Box<dyn Write + Send>> {
+        &self.output
+    }
+
+    fn error(&self) -> &Mutex<Box<dyn Write + Send>> {
+        &self.error
     }
 }
@@ -0,0 +1,84 @@
+//! A subcommand used to assume an IAM role via STS, for users authenticating with long-lived
+//! credentials rather than SSO.
+
+use crate::app::{self, ErrorContext};
+use crate::util::{profile_name, sts, Shell};
+use clap::ValueEnum;
+
+/// The name of the environment variable the shell integration uses to report which shell
+/// invoked this application.
+const SHELL_VAR: &str = "AWS_LOGIN_SHELL";
+
+/// The options for the subcommand.
+#[derive(clap::Parser)]
+pub struct Subcommand {
+    /// The ARN of the role to assume.
+    #[clap(long)]
+    role_arn: String,
+
+    /// The ARN of the MFA device to authenticate with, if the role requires it.
+    #[clap(long)]
+    mfa_serial: Option<String>,
+
+    /// The current MFA token code. Prompted for interactively if an MFA serial is given but no
+    /// code is.
+    #[clap(long)]
+    token_code: Option<String>,
+
+    /// How long the assumed role's credentials should remain valid for, in seconds.
+    #[clap(long)]
+    duration_seconds: Option<i32>,
+}
+
+impl app::Execute for Subcommand {
+    fn execute(&self, context: &mut impl app::Context) -> app::Result<()> {
+        let profile = profile_name(context);
+
+        let credentials = sts::assume_role(
+            context,
+            &profile,
+            sts::AssumeRoleRequest {
+                role_arn: &self.role_arn,
+                mfa_serial: self.mfa_serial.as_deref(),
+                token_code: self.token_code.as_deref(),
+                duration_seconds: self.duration_seconds,
+            },
+        )
+        .with_context(|| format!("Could not assume role `{}`.", self.role_arn))?;
+
+        let shell_name = std::env::var(SHELL_VAR).map_err(|_| {
+            app::Error::new(1).with_message(format!(
+                "{} is not set; run this through the generated shell integration.",
+                SHELL_VAR
+            ))
+        })?;
+
+        let shell = Shell::from_str(&shell_name, true)
+            .map_err(|message| app::Error::new(1).with_message(message))?;
+
+        let mut environment = shell.environment();
+
+        environment.set_var("AWS_ACCESS_KEY_ID", &credentials.access_key_id)?;
+        environment.set_var("AWS_SECRET_ACCESS_KEY", &credentials.secret_access_key)?;
+        environment.set_var("AWS_SESSION_TOKEN", &credentials.session_token)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn shell_parses_the_literal_value_its_own_init_script_writes_to_shell_var() {
+        for name in ["bash", "fish", "powershell", "zsh"] {
+            assert!(
+                Shell::from_str(name, true).is_ok(),
+                "`{}` read back from {} should parse",
+                name,
+                SHELL_VAR
+            );
+        }
+    }
+}
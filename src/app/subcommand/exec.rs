@@ -0,0 +1,52 @@
+//! A subcommand used to run another program with resolved AWS credentials injected.
+
+use crate::app::{self, ErrorContext};
+use crate::util::{credentials, load_profile, run};
+use std::process;
+
+/// The options for the subcommand.
+#[derive(clap::Parser)]
+pub struct Subcommand {
+    /// The command, and any arguments, to run with credentials injected into its environment.
+    #[clap(required = true, trailing_var_arg = true)]
+    command: Vec<String>,
+}
+
+impl app::Execute for Subcommand {
+    fn execute(&self, context: &mut impl app::Context) -> app::Result<()> {
+        let profile = load_profile(context)?;
+
+        let resolved = credentials::resolve(context, &profile)
+            .with_context(|| "Could not resolve credentials for the active profile.".to_owned())?;
+
+        let (program, arguments) = self
+            .command
+            .split_first()
+            .expect("clap guarantees at least one argument is present");
+
+        let mut child = run::Run::new(program);
+
+        for argument in arguments {
+            child.arg(argument);
+        }
+
+        child.env("AWS_ACCESS_KEY_ID", &resolved.access_key_id);
+        child.env("AWS_SECRET_ACCESS_KEY", &resolved.secret_access_key);
+        child.env("AWS_SESSION_TOKEN", &resolved.session_token);
+
+        let region = context
+            .region()
+            .map(str::to_owned)
+            .or_else(|| profile.get("region").cloned());
+
+        if let Some(region) = region {
+            child.env("AWS_REGION", &region);
+        }
+
+        let status = child
+            .status()
+            .with_context(|| format!("Could not execute `{}`.", program))?;
+
+        process::exit(status);
+    }
+}
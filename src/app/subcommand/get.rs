@@ -0,0 +1,30 @@
+//! A subcommand used to emit credentials in the `credential_process` JSON protocol AWS SDKs
+//! expect from a helper program.
+
+use crate::app;
+use crate::util::{credentials, load_profile};
+use std::io::Write;
+
+/// The options for the subcommand.
+#[derive(clap::Parser)]
+pub struct Subcommand {}
+
+impl app::Execute for Subcommand {
+    fn execute(&self, context: &mut impl app::Context) -> app::Result<()> {
+        let profile = load_profile(context)?;
+        let resolved = credentials::resolve(context, &profile)?;
+
+        writeln!(context.output().lock().unwrap(), "{}", to_json(&resolved))?;
+
+        Ok(())
+    }
+}
+
+/// Serializes resolved credentials into the JSON object AWS SDKs expect from a
+/// `credential_process` helper.
+fn to_json(credentials: &credentials::Credentials) -> String {
+    format!(
+        "{{\"Version\":1,\"AccessKeyId\":\"{}\",\"SecretAccessKey\":\"{}\",\"SessionToken\":\"{}\",\"Expiration\":\"{}\"}}",
+        credentials.access_key_id, credentials.secret_access_key, credentials.session_token, credentials.expiration
+    )
+}
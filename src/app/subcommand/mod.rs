@@ -0,0 +1,150 @@
+//! Defines the subcommands available on the command line and the machinery they share.
+
+pub mod assume_role;
+pub mod exec;
+pub mod get;
+pub mod shell;
+pub mod sso;
+
+use std::io::Write;
+use std::sync::Mutex;
+
+/// The error type returned when a subcommand fails.
+#[derive(Debug)]
+pub struct Error {
+    /// The status code the process should exit with.
+    status: i32,
+
+    /// A human readable message describing the failure, if any.
+    message: Option<String>,
+}
+
+impl Error {
+    /// Creates a new error that will cause the process to exit with the given status.
+    pub fn new(status: i32) -> Self {
+        Self {
+            status,
+            message: None,
+        }
+    }
+
+    /// Attaches a message to the error.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Returns the status code the process should exit with.
+    pub fn status(&self) -> i32 {
+        self.status
+    }
+
+    /// Returns the message associated with the error, if any.
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match &self.message {
+            Some(message) => write!(formatter, "{}", message),
+            None => write!(formatter, "exited with status {}", self.status),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Self::new(error.raw_os_error().unwrap_or(1)).with_message(error.to_string())
+    }
+}
+
+/// The result type returned by subcommands and the utilities they rely on.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Attaches additional context to a failed [`Result`].
+pub trait ErrorContext<T> {
+    /// Replaces the message of the error, preserving its status code.
+    fn with_context(self, context: impl FnOnce() -> String) -> Result<T>;
+}
+
+impl<T, E: Into<Error>> ErrorContext<T> for std::result::Result<T, E> {
+    fn with_context(self, context: impl FnOnce() -> String) -> Result<T> {
+        self.map_err(|error| Into::<Error>::into(error).with_message(context()))
+    }
+}
+
+/// Builds and returns a [`Error`], stopping execution of the current function.
+///
+/// ```ignore
+/// if !status.success() {
+///     err!(status.code().unwrap_or(1));
+/// }
+/// ```
+#[macro_export]
+macro_rules! err {
+    ($status:expr) => {
+        return Err($crate::app::Error::new($status))
+    };
+    ($status:expr, $message:expr) => {
+        return Err($crate::app::Error::new($status).with_message($message))
+    };
+    ($status:expr, $fmt:expr, $($args:tt)*) => {
+        return Err($crate::app::Error::new($status).with_message(format!($fmt, $($args)*)))
+    };
+}
+
+/// Exposes the request-scoped information a subcommand needs to execute.
+pub trait Context {
+    /// The explicit profile requested on the command line, if any.
+    fn profile(&self) -> Option<&str>;
+
+    /// The explicit region requested on the command line, if any.
+    fn region(&self) -> Option<&str>;
+
+    /// The stream standard output should be written to.
+    fn output(&self) -> &Mutex<Box<dyn Write + Send>>;
+
+    /// The stream error output should be written to.
+    fn error(&self) -> &Mutex<Box<dyn Write + Send>>;
+}
+
+/// Implemented by each subcommand so it can be executed against a [`Context`].
+pub trait Execute {
+    /// Runs the subcommand to completion.
+    fn execute(&self, context: &mut impl Context) -> Result<()>;
+}
+
+/// AWS account or managed service to log into, or action to perform using resolved credentials.
+#[derive(clap::Parser)]
+pub enum Subcommand {
+    /// Assume an IAM role via STS using long-lived base credentials.
+    AssumeRole(assume_role::Subcommand),
+
+    /// Execute a command with resolved AWS credentials injected into its environment.
+    Exec(exec::Subcommand),
+
+    /// Print the active profile's credentials using the `credential_process` JSON protocol.
+    Get(get::Subcommand),
+
+    /// Manage shell integration.
+    Shell(shell::Subcommand),
+
+    /// Authenticate into an AWS account using SSO.
+    Sso(sso::Subcommand),
+}
+
+impl Execute for Subcommand {
+    fn execute(&self, context: &mut impl Context) -> Result<()> {
+        match self {
+            Self::AssumeRole(cmd) => cmd.execute(context),
+            Self::Exec(cmd) => cmd.execute(context),
+            Self::Get(cmd) => cmd.execute(context),
+            Self::Shell(cmd) => cmd.execute(context),
+            Self::Sso(cmd) => cmd.execute(context),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+//! A subcommand used to manage shell integration.
+
+use crate::app;
+use crate::util::Shell;
+use std::io::Write;
+
+/// The options for the subcommand.
+#[derive(clap::Parser)]
+pub struct Subcommand {
+    /// The action to perform for the selected shell.
+    #[clap(subcommand)]
+    action: Action,
+}
+
+/// The actions available for managing shell integration.
+#[derive(clap::Parser)]
+enum Action {
+    /// Prints the script the shell should evaluate on startup.
+    Init {
+        /// The shell to generate the integration script for.
+        #[clap(short, long)]
+        shell: Shell,
+    },
+
+    /// Installs the integration into the shell's profile startup script.
+    Install {
+        /// The shell to install the integration for.
+        #[clap(short, long)]
+        shell: Shell,
+
+        /// The path to the profile startup script, if not the default for the shell.
+        #[clap(long)]
+        profile: Option<String>,
+    },
+}
+
+impl app::Execute for Subcommand {
+    fn execute(&self, context: &mut impl app::Context) -> app::Result<()> {
+        match &self.action {
+            Action::Init { shell } => {
+                let script = shell.setup(None).generate_script();
+
+                write!(context.output().lock().unwrap(), "{}", script)?;
+            }
+            Action::Install { shell, profile } => {
+                let setup = shell.setup(profile.as_deref());
+
+                if !setup.is_installed()? {
+                    setup.install()?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
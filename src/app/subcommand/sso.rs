@@ -1,7 +1,8 @@
 //! A subcommand used to authenticate into an AWS account using SSO.
 
 use crate::app::{self, ErrorContext};
-use crate::util::run;
+use crate::util::{load_profile, run, sso, Profile};
+use std::io::Write;
 
 /// The profile configuration settings required for SSO.
 const REQUIRED_SETTINGS: &[&str] = &[
@@ -13,11 +14,39 @@ const REQUIRED_SETTINGS: &[&str] = &[
 
 /// The options for the subcommand.
 #[derive(clap::Parser)]
-pub struct Subcommand {}
+pub struct Subcommand {
+    /// Authenticate using this application's own device-authorization flow instead of shelling
+    /// out to the AWS CLI.
+    #[clap(long)]
+    native: bool,
+
+    /// Log in again even if a cached SSO session is still valid.
+    #[clap(long)]
+    force: bool,
+}
 
 impl app::Execute for Subcommand {
     fn execute(&self, context: &mut impl app::Context) -> app::Result<()> {
-        if is_configured(context)? {
+        let profile = load_profile(context)?;
+
+        if !self.force {
+            if let Some(start_url) = profile.get("sso_start_url") {
+                if sso::is_session_valid(context, start_url)? {
+                    writeln!(
+                        context.output().lock().unwrap(),
+                        "Already logged in via SSO; skipping (use --force to log in again)."
+                    )?;
+
+                    return Ok(());
+                }
+            }
+        }
+
+        if self.native {
+            return sso::login(context, &profile).with_context(|| "Could not log in via SSO.".to_owned());
+        }
+
+        if is_configured(&profile) {
             run::Run::new("aws")
                 .with_aws_options(context)
                 .arg("sso")
@@ -38,22 +67,8 @@ impl app::Execute for Subcommand {
 }
 
 /// Checks if the active profile is fully configured for SSO.
-fn is_configured(context: &impl app::Context) -> app::Result<bool> {
-    let mut has = 0;
-
-    for key in REQUIRED_SETTINGS {
-        if let Ok(value) = run::Run::new("aws")
-            .with_aws_options(context)
-            .arg("configure")
-            .arg("get")
-            .arg(key)
-            .output()
-        {
-            if !value.trim().is_empty() {
-                has += 1;
-            }
-        };
-    }
-
-    Ok(has == REQUIRED_SETTINGS.len())
+fn is_configured(profile: &Profile) -> bool {
+    REQUIRED_SETTINGS
+        .iter()
+        .all(|key| profile.get(*key).is_some_and(|value| !value.trim().is_empty()))
 }
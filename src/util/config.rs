@@ -0,0 +1,159 @@
+//! Reads configuration from the shared AWS config file.
+
+use crate::app;
+use std::collections::HashMap;
+use std::{env, fs, io, path};
+
+/// The name of the environment variable used to override the location of the AWS config file.
+const CONFIG_FILE_VAR: &str = "AWS_CONFIG_FILE";
+
+/// The name of the environment variable used to override the active profile.
+const PROFILE_VAR: &str = "AWS_PROFILE";
+
+/// The settings defined for a single profile in the AWS config file.
+pub type Profile = HashMap<String, String>;
+
+/// The name this binary was invoked as, used when instructing users how to invoke it.
+pub static BIN_NAME: once_cell::sync::Lazy<String> = once_cell::sync::Lazy::new(|| {
+    env::current_exe()
+        .ok()
+        .and_then(|path| path.file_name().map(|name| name.to_string_lossy().into_owned()))
+        .unwrap_or_else(|| env!("CARGO_PKG_NAME").to_owned())
+});
+
+/// Resolves the name of the active profile.
+///
+/// The active profile is resolved from [`app::Context::profile`], then the `AWS_PROFILE`
+/// environment variable, and finally falls back to `"default"`. Anything that caches state per
+/// profile (such as the SSO and STS credential caches) should key off this instead of reading
+/// [`app::Context::profile`] directly, so the cache key always matches the profile
+/// [`load_profile`] actually read settings from.
+pub fn profile_name(context: &impl app::Context) -> String {
+    context
+        .profile()
+        .map(str::to_owned)
+        .or_else(|| env::var(PROFILE_VAR).ok())
+        .unwrap_or_else(|| "default".to_owned())
+}
+
+/// Loads the settings for the active profile from the shared AWS config file.
+///
+/// The default profile lives under the `[default]` section, while named profiles live under
+/// `[profile <name>]`, matching the layout the AWS CLI itself uses.
+///
+/// ```
+/// use crate::util::config;
+///
+/// let profile = config::load_profile(context)?;
+/// let start_url = profile.get("sso_start_url");
+/// ```
+pub fn load_profile(context: &impl app::Context) -> app::Result<Profile> {
+    let name = profile_name(context);
+
+    let section = if name == "default" {
+        name
+    } else {
+        format!("profile {}", name)
+    };
+
+    let sections = load_config_file()?;
+
+    Ok(sections.get(&section).cloned().unwrap_or_default())
+}
+
+/// Parses the shared AWS config file into a map of section name to its settings.
+fn load_config_file() -> app::Result<HashMap<String, Profile>> {
+    let contents = match fs::read_to_string(config_file_path()) {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(HashMap::new()),
+        Err(error) => return Err(error.into()),
+    };
+
+    Ok(parse_ini(&contents))
+}
+
+/// Resolves the path to the shared AWS config file.
+fn config_file_path() -> path::PathBuf {
+    env::var(CONFIG_FILE_VAR)
+        .map(path::PathBuf::from)
+        .unwrap_or_else(|_| {
+            home::home_dir()
+                .expect("The home directory could not be determined.")
+                .join(".aws")
+                .join("config")
+        })
+}
+
+/// Parses INI-formatted text into a map of section name to its key/value settings.
+fn parse_ini(contents: &str) -> HashMap<String, Profile> {
+    let mut sections: HashMap<String, Profile> = HashMap::new();
+    let mut current: Option<String> = None;
+
+    for line in contents.lines() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('[').and_then(|rest| rest.strip_suffix(']')) {
+            let name = name.trim().to_owned();
+            sections.entry(name.clone()).or_default();
+            current = Some(name);
+            continue;
+        }
+
+        if let Some(section) = &current {
+            if let Some((key, value)) = line.split_once('=') {
+                sections
+                    .entry(section.clone())
+                    .or_default()
+                    .insert(key.trim().to_owned(), value.trim().to_owned());
+            }
+        }
+    }
+
+    sections
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_default_and_named_profiles() {
+        let sections = parse_ini(
+            "[default]\n\
+             sso_start_url = https://example.awsapps.com/start\n\
+             [profile dev]\n\
+             sso_start_url = https://dev.awsapps.com/start\n\
+             sso_role_name = Developer\n",
+        );
+
+        assert_eq!(
+            sections.get("default").unwrap().get("sso_start_url"),
+            Some(&"https://example.awsapps.com/start".to_owned())
+        );
+
+        assert_eq!(
+            sections.get("profile dev").unwrap().get("sso_role_name"),
+            Some(&"Developer".to_owned())
+        );
+    }
+
+    #[test]
+    fn ignores_comments_and_blank_lines() {
+        let sections = parse_ini(
+            "; a comment\n\
+             [default]\n\
+             # another comment\n\
+             \n\
+             region = us-east-1\n",
+        );
+
+        assert_eq!(
+            sections.get("default").unwrap().get("region"),
+            Some(&"us-east-1".to_owned())
+        );
+    }
+}
@@ -0,0 +1,117 @@
+//! Resolves credentials for the active profile natively, without shelling out to the AWS CLI.
+
+use crate::app;
+use crate::err;
+use crate::util::config::{self, Profile, BIN_NAME};
+use crate::util::{sso, sts};
+
+/// Temporary credentials resolved for the active profile.
+pub struct Credentials {
+    /// The temporary access key id.
+    pub access_key_id: String,
+
+    /// The temporary secret access key.
+    pub secret_access_key: String,
+
+    /// The temporary session token.
+    pub session_token: String,
+
+    /// The RFC 3339 timestamp the credentials expire at.
+    pub expiration: String,
+}
+
+/// Resolves credentials for the given profile from the native SSO or STS caches.
+///
+/// Neither an SSO login nor an MFA prompt is ever driven from here: callers of this function are
+/// meant to run non-interactively, and both would hang or corrupt a caller's own output with no
+/// terminal attached to answer them. A stale SSO session or a role requiring MFA must be
+/// refreshed ahead of time with `sso` or `assume-role`, respectively.
+pub fn resolve(context: &mut impl app::Context, profile: &Profile) -> app::Result<Credentials> {
+    if let Some(start_url) = profile.get("sso_start_url") {
+        resolve_sso_credentials(context, start_url)
+    } else if let Some(role_arn) = profile.get("role_arn") {
+        resolve_role_credentials(context, profile, role_arn)
+    } else {
+        err!(
+            1,
+            "The active profile has no `sso_start_url` or `role_arn` to resolve credentials from."
+        );
+    }
+}
+
+/// Resolves credentials for a profile configured for SSO.
+fn resolve_sso_credentials(context: &mut impl app::Context, start_url: &str) -> app::Result<Credentials> {
+    if !sso::is_session_valid(context, start_url)? {
+        err!(
+            1,
+            "The cached SSO session has expired; run `{} --profile {} sso --native` to refresh it.",
+            *BIN_NAME,
+            config::profile_name(context)
+        );
+    }
+
+    let entry = sso::cache::read(start_url)?
+        .ok_or_else(|| app::Error::new(1).with_message("No cached SSO session was found."))?;
+
+    Ok(Credentials {
+        access_key_id: entry.access_key_id,
+        secret_access_key: entry.secret_access_key,
+        session_token: entry.session_token,
+        expiration: entry.credentials_expire_at,
+    })
+}
+
+/// Resolves credentials for a profile configured to assume a role, reusing a cached session if
+/// one is still valid.
+///
+/// Roles requiring MFA are never assumed here; such a profile must be refreshed ahead of time
+/// with `assume-role`.
+fn resolve_role_credentials(
+    context: &mut impl app::Context,
+    profile: &Profile,
+    role_arn: &str,
+) -> app::Result<Credentials> {
+    let profile_key = config::profile_name(context);
+    let mfa_serial = profile.get("mfa_serial").map(String::as_str);
+
+    if let Some(mfa_serial) = mfa_serial {
+        let entry = sts::cached_credentials(&profile_key, role_arn)?.ok_or_else(|| {
+            app::Error::new(1).with_message(format!(
+                "The cached session for role `{}` has expired; run `{} --profile {} assume-role --role-arn {} --mfa-serial {}` to refresh it.",
+                role_arn,
+                *BIN_NAME,
+                profile_key,
+                role_arn,
+                mfa_serial
+            ))
+        })?;
+
+        return Ok(Credentials {
+            access_key_id: entry.access_key_id,
+            secret_access_key: entry.secret_access_key,
+            session_token: entry.session_token,
+            expiration: entry.expiration,
+        });
+    }
+
+    let credentials = sts::assume_role(
+        context,
+        &profile_key,
+        sts::AssumeRoleRequest {
+            role_arn,
+            mfa_serial: None,
+            token_code: None,
+            duration_seconds: None,
+        },
+    )?;
+
+    let entry = sts::cache::read(&profile_key, role_arn)?
+        .ok_or_else(|| app::Error::new(1).with_message("No cached role session was found."))?;
+
+    Ok(Credentials {
+        access_key_id: credentials.access_key_id,
+        secret_access_key: credentials.secret_access_key,
+        session_token: credentials.session_token,
+        expiration: entry.expiration,
+    })
+}
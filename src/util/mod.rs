@@ -2,8 +2,15 @@
 
 mod command;
 mod config;
+pub mod credentials;
 mod interface;
+pub mod run;
 mod shell;
+pub mod sso;
+pub mod sts;
+
+#[cfg(test)]
+pub(crate) mod test;
 
 pub use command::*;
 pub use config::*;
@@ -36,6 +36,17 @@ impl Run {
         self
     }
 
+    /// Sets an environment variable on the process builder.
+    ///
+    /// ```
+    /// let mut run = Run::new("my-app").env("NAME", "value");
+    /// ```
+    pub fn env(&mut self, name: &str, value: &str) -> &mut Self {
+        self.builder.env(name, value);
+
+        self
+    }
+
     /// Returns the arguments added to the builder.
     ///
     /// ```
@@ -186,6 +197,31 @@ impl Run {
         })
     }
 
+    /// Runs the command, inheriting stdio, and returns its exit status code.
+    ///
+    /// Unlike [`Run::output`] and [`Run::pass_through`], a non-zero exit is not treated as an
+    /// [`Err`]; the caller is expected to propagate the returned status code itself, as when
+    /// execing a wrapped command.
+    ///
+    /// ```
+    /// use crate::util::run;
+    ///
+    /// let status = run::Run::new("terraform").arg("apply").status()?;
+    /// ```
+    pub fn status(&mut self) -> Result<i32> {
+        Runtime::new()?.block_on(async {
+            let status = self
+                .builder
+                .stdin(Stdio::inherit())
+                .stdout(Stdio::inherit())
+                .stderr(Stdio::inherit())
+                .status()
+                .await?;
+
+            Ok(status.code().unwrap_or(1))
+        })
+    }
+
     /// Assumes that the AWS CLI is being invoked and adds additional arguments.
     ///
     /// The given context will be used to add the `--profile` and `--region` options for the AWS
@@ -274,4 +310,26 @@ mod test {
         assert!(result.is_ok());
         assert_eq!(context.output_as_string(), "Hello, world!");
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn pass_through_error() {
+        let mut context = TestContext::default();
+
+        let result = Run::new("sh")
+            .arg("-c")
+            .arg("echo error >&2")
+            .pass_through(&mut context);
+
+        assert!(result.is_ok());
+        assert_eq!(context.error_as_string(), "error\n");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn exit_status() {
+        let result = Run::new("sh").arg("-c").arg("exit 3").status();
+
+        assert_eq!(result.unwrap(), 3);
+    }
 }
@@ -23,6 +23,9 @@ const INSTALLED_COMMENT: &str = "# Integrate aws-login into the shell environmen
 /// exits, the parent process is expected to evaluate and then clean up the file.
 const SCRIPT_PATH: &str = "AWS_LOGIN_SCRIPT";
 
+/// The value written to `AWS_LOGIN_SHELL` so subcommands know which [`Environment`] to use.
+pub(super) const SHELL_NAME: &str = "fish";
+
 /// Manages the current Fish environment.
 pub struct Environment {
     /// The file that will be used to evaluate shell code.
@@ -31,7 +34,7 @@ pub struct Environment {
 
 impl super::Environment for Environment {
     fn set_var(&mut self, name: &str, value: &str) -> crate::app::Result<()> {
-        write!(self.file, "set -gx {} \"{}\"", name, value)
+        writeln!(self.file, "set -gx {} \"{}\"", name, value)
             .map_err(app::Error::from)
             .with_context(|| "Could not set environment variable.".to_owned())
     }
@@ -74,7 +77,7 @@ impl super::Setup for Setup {
     fn generate_script(&self) -> String {
         include_str!("init.fish")
             .replace("{AWS_LOGIN}", &config::BIN_NAME)
-            .replace("{AWS_LOGIN_SHELL}", super::SHELL_NAME)
+            .replace("{AWS_LOGIN_SHELL}", SHELL_NAME)
     }
 
     fn install(&self) -> app::Result<()> {
@@ -0,0 +1,96 @@
+//! Provides shell integration so resolved credentials can be exported into the user's
+//! environment without mutating it from a child process directly.
+//!
+//! Each supported shell implements [`Environment`] to write out variable changes and [`Setup`]
+//! to install and generate the script the shell evaluates on startup.
+
+mod bash;
+mod fish;
+mod powershell;
+mod zsh;
+
+use crate::app;
+
+/// Manages writing environment variable changes into the active shell's evaluation script.
+pub trait Environment {
+    /// Sets an environment variable for the shell to pick up once the script is evaluated.
+    fn set_var(&mut self, name: &str, value: &str) -> app::Result<()>;
+}
+
+/// Manages installing and generating the integration script for a shell.
+pub trait Setup {
+    /// Generates the script that should be evaluated by the shell on startup.
+    fn generate_script(&self) -> String;
+
+    /// Installs the integration into the shell's profile startup script.
+    fn install(&self) -> app::Result<()>;
+
+    /// Checks whether the integration has already been installed.
+    fn is_installed(&self) -> app::Result<bool>;
+}
+
+/// The shells supported for integration.
+#[derive(Clone, Copy, clap::ValueEnum)]
+#[allow(clippy::enum_variant_names)]
+pub enum Shell {
+    /// The Bash shell.
+    Bash,
+
+    /// The Fish shell.
+    Fish,
+
+    /// PowerShell.
+    ///
+    /// Named explicitly as `powershell` rather than clap's default kebab-case `power-shell`, to
+    /// match the literal each `powershell::mod` hardcodes into `AWS_LOGIN_SHELL` and the
+    /// generated `shell init -s powershell` line.
+    #[value(name = "powershell")]
+    PowerShell,
+
+    /// The Zsh shell.
+    Zsh,
+}
+
+impl Shell {
+    /// Creates the [`Environment`] implementation for this shell.
+    pub fn environment(&self) -> Box<dyn Environment> {
+        match self {
+            Self::Bash => Box::new(bash::Environment::default()),
+            Self::Fish => Box::new(fish::Environment::default()),
+            Self::PowerShell => Box::new(powershell::Environment::default()),
+            Self::Zsh => Box::new(zsh::Environment::default()),
+        }
+    }
+
+    /// Creates the [`Setup`] implementation for this shell.
+    pub fn setup(&self, profile: Option<&str>) -> Box<dyn Setup> {
+        match self {
+            Self::Bash => Box::new(bash::Setup::new(profile)),
+            Self::Fish => Box::new(fish::Setup::new(profile)),
+            Self::PowerShell => Box::new(powershell::Setup::new(profile)),
+            Self::Zsh => Box::new(zsh::Setup::new(profile)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use clap::ValueEnum;
+
+    #[test]
+    fn from_str_accepts_each_shell_name_constant() {
+        for name in [
+            bash::SHELL_NAME,
+            fish::SHELL_NAME,
+            powershell::SHELL_NAME,
+            zsh::SHELL_NAME,
+        ] {
+            assert!(
+                Shell::from_str(name, true).is_ok(),
+                "`{}` should be a valid --shell value",
+                name
+            );
+        }
+    }
+}
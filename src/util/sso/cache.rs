@@ -0,0 +1,151 @@
+//! Reads and writes the SSO credential cache shared with the AWS CLI and SDKs under
+//! `~/.aws/sso/cache`.
+
+use crate::app;
+use sha1::{Digest, Sha1};
+use std::{fs, path};
+
+/// A cached SSO session for a single start URL.
+#[derive(Clone, Default)]
+pub struct Entry {
+    /// The SSO start URL this entry was cached for.
+    pub start_url: String,
+
+    /// The SSO region.
+    pub region: String,
+
+    /// The OIDC access token used to request role credentials.
+    pub access_token: String,
+
+    /// The RFC 3339 timestamp the cached session expires at.
+    pub expires_at: String,
+
+    /// The registered OIDC client id, reused so future logins skip re-registration.
+    pub client_id: String,
+
+    /// The registered OIDC client secret.
+    pub client_secret: String,
+
+    /// The temporary access key id resolved for the configured role, if any.
+    pub access_key_id: String,
+
+    /// The temporary secret access key resolved for the configured role, if any.
+    pub secret_access_key: String,
+
+    /// The temporary session token resolved for the configured role, if any.
+    pub session_token: String,
+
+    /// The RFC 3339 timestamp the resolved role credentials expire at, if any.
+    ///
+    /// This is distinct from [`Entry::expires_at`], which tracks the much longer-lived OIDC
+    /// session rather than the role credentials it was used to obtain.
+    pub credentials_expire_at: String,
+}
+
+/// Reads the cached session for the given start URL, if one exists.
+pub fn read(start_url: &str) -> app::Result<Option<Entry>> {
+    let path = cache_path(start_url);
+
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let contents = fs::read_to_string(path)?;
+
+    Ok(Some(Entry {
+        start_url: start_url.to_owned(),
+        region: field(&contents, "region").unwrap_or_default(),
+        access_token: field(&contents, "accessToken").unwrap_or_default(),
+        expires_at: field(&contents, "expiresAt").unwrap_or_default(),
+        client_id: field(&contents, "clientId").unwrap_or_default(),
+        client_secret: field(&contents, "clientSecret").unwrap_or_default(),
+        access_key_id: field(&contents, "accessKeyId").unwrap_or_default(),
+        secret_access_key: field(&contents, "secretAccessKey").unwrap_or_default(),
+        session_token: field(&contents, "sessionToken").unwrap_or_default(),
+        credentials_expire_at: field(&contents, "credentialsExpireAt").unwrap_or_default(),
+    }))
+}
+
+/// Writes a session to the cache, replacing any existing entry for the same start URL.
+pub fn write(entry: &Entry) -> app::Result<()> {
+    let path = cache_path(&entry.start_url);
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let json = format!(
+        "{{\n  \
+           \"startUrl\": \"{}\",\n  \
+           \"region\": \"{}\",\n  \
+           \"accessToken\": \"{}\",\n  \
+           \"expiresAt\": \"{}\",\n  \
+           \"clientId\": \"{}\",\n  \
+           \"clientSecret\": \"{}\",\n  \
+           \"accessKeyId\": \"{}\",\n  \
+           \"secretAccessKey\": \"{}\",\n  \
+           \"sessionToken\": \"{}\",\n  \
+           \"credentialsExpireAt\": \"{}\"\n\
+         }}\n",
+        entry.start_url,
+        entry.region,
+        entry.access_token,
+        entry.expires_at,
+        entry.client_id,
+        entry.client_secret,
+        entry.access_key_id,
+        entry.secret_access_key,
+        entry.session_token,
+        entry.credentials_expire_at,
+    );
+
+    fs::write(path, json)?;
+
+    Ok(())
+}
+
+/// Extracts a top-level string field from a minimal JSON document.
+fn field(contents: &str, name: &str) -> Option<String> {
+    let needle = format!("\"{}\"", name);
+    let after_key = &contents[contents.find(&needle)? + needle.len()..];
+    let after_colon = &after_key[after_key.find(':')? + 1..].trim_start();
+    let after_quote = after_colon.strip_prefix('"')?;
+    let end = after_quote.find('"')?;
+
+    Some(after_quote[..end].to_owned())
+}
+
+/// Computes the path to the cache file for a given start URL.
+///
+/// The AWS CLI and SDKs key cache entries by the lowercase hex-encoded SHA1 digest of the start
+/// URL, so this tool reuses the same entries they produce.
+fn cache_path(start_url: &str) -> path::PathBuf {
+    let digest = Sha1::digest(start_url.as_bytes());
+    let name = digest.iter().map(|byte| format!("{:02x}", byte)).collect::<String>();
+
+    cache_dir().join(format!("{}.json", name))
+}
+
+/// Resolves the directory the AWS CLI and SDKs share for the SSO credential cache.
+fn cache_dir() -> path::PathBuf {
+    home::home_dir()
+        .expect("The home directory could not be determined.")
+        .join(".aws")
+        .join("sso")
+        .join("cache")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn extracts_a_top_level_string_field() {
+        let contents = "{\n  \"startUrl\": \"https://example.awsapps.com/start\",\n  \"expiresAt\": \"2030-01-01T00:00:00Z\"\n}";
+
+        assert_eq!(
+            field(contents, "expiresAt").as_deref(),
+            Some("2030-01-01T00:00:00Z")
+        );
+    }
+}
@@ -0,0 +1,259 @@
+//! Implements the SSO OIDC device-authorization flow, without depending on the AWS CLI, and
+//! exchanges the resulting session for temporary role credentials.
+
+pub mod cache;
+
+use crate::app;
+use crate::util::config::Profile;
+use aws_sdk_ssooidc as ssooidc;
+use chrono::TimeZone;
+use std::io::Write;
+use std::time::Duration;
+use tokio::runtime::Runtime;
+use tokio::time::sleep;
+
+/// The name this application registers itself as when requesting an OIDC client.
+const CLIENT_NAME: &str = "aws-login";
+
+/// The type of OIDC client this application registers as.
+const CLIENT_TYPE: &str = "public";
+
+/// The grant type used to exchange a device code for an access token.
+const DEVICE_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:device_code";
+
+/// A cached SSO session expiring within this many minutes is treated as already stale, so a
+/// fresh device-authorization flow has time to complete before the old session actually expires.
+const EXPIRY_MARGIN_MINUTES: i64 = 5;
+
+/// Checks whether a cached session exists for the given start URL and is not stale.
+pub fn is_session_valid(context: &mut impl app::Context, start_url: &str) -> app::Result<bool> {
+    let Some(entry) = cache::read(start_url)? else {
+        return Ok(false);
+    };
+
+    let Some(expires_at) = parse_expires_at(&entry.expires_at) else {
+        writeln!(
+            context.error().lock().unwrap(),
+            "Warning: could not parse the cached session's expiry (`{}`); logging in again.",
+            entry.expires_at
+        )?;
+
+        return Ok(false);
+    };
+
+    let margin = chrono::Duration::minutes(EXPIRY_MARGIN_MINUTES);
+
+    Ok(expires_at > chrono::Utc::now() + margin)
+}
+
+/// Parses a cached session's expiry timestamp.
+///
+/// The AWS CLI has historically written `expiresAt` without a UTC timezone suffix, so an RFC
+/// 3339 parse is tried first and a naive, assumed-UTC parse is tried as a fallback.
+fn parse_expires_at(value: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    if let Ok(parsed) = chrono::DateTime::parse_from_rfc3339(value) {
+        return Some(parsed.with_timezone(&chrono::Utc));
+    }
+
+    chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S")
+        .ok()
+        .map(|naive| chrono::Utc.from_utc_datetime(&naive))
+}
+
+/// Performs the SSO device-authorization flow for the given profile.
+///
+/// The user is shown a verification URL and code through the [`app::Context`] output stream
+/// (and the URL is opened in a browser, best effort). Once they complete verification, the
+/// resulting session is exchanged for role credentials and both are written into the SSO
+/// credential cache.
+pub fn login(context: &mut impl app::Context, profile: &Profile) -> app::Result<()> {
+    let start_url = required(profile, "sso_start_url")?;
+    let region = required(profile, "sso_region")?;
+    let account_id = required(profile, "sso_account_id")?;
+    let role_name = required(profile, "sso_role_name")?;
+
+    Runtime::new()?.block_on(async {
+        let shared_config = aws_config::defaults(aws_config::BehaviorVersion::latest())
+            .region(aws_config::Region::new(region.clone()))
+            .load()
+            .await;
+
+        let oidc = ssooidc::Client::new(&shared_config);
+
+        let client = oidc
+            .register_client()
+            .client_name(CLIENT_NAME)
+            .client_type(CLIENT_TYPE)
+            .send()
+            .await
+            .map_err(|error| app::Error::new(1).with_message(error.to_string()))?;
+
+        let client_id = client.client_id().unwrap_or_default().to_owned();
+        let client_secret = client.client_secret().unwrap_or_default().to_owned();
+
+        let authorization = oidc
+            .start_device_authorization()
+            .client_id(&client_id)
+            .client_secret(&client_secret)
+            .start_url(&start_url)
+            .send()
+            .await
+            .map_err(|error| app::Error::new(1).with_message(error.to_string()))?;
+
+        {
+            let mut output = context.output().lock().unwrap();
+
+            writeln!(
+                output,
+                "To authenticate, go to {} and confirm the code: {}",
+                authorization.verification_uri_complete().unwrap_or_default(),
+                authorization.user_code().unwrap_or_default(),
+            )?;
+        }
+
+        let _ = webbrowser::open(authorization.verification_uri_complete().unwrap_or_default());
+
+        let interval = Duration::from_secs(authorization.interval().max(1) as u64);
+
+        let token = poll_for_token(
+            &oidc,
+            &client_id,
+            &client_secret,
+            authorization.device_code().unwrap_or_default(),
+            interval,
+        )
+        .await?;
+
+        let access_token = token.access_token().unwrap_or_default().to_owned();
+        let expires_at = (chrono::Utc::now() + chrono::Duration::seconds(token.expires_in() as i64))
+            .to_rfc3339();
+
+        let role_credentials = aws_sdk_sso::Client::new(&shared_config)
+            .get_role_credentials()
+            .access_token(&access_token)
+            .account_id(&account_id)
+            .role_name(&role_name)
+            .send()
+            .await
+            .map_err(|error| app::Error::new(1).with_message(error.to_string()))?
+            .role_credentials()
+            .cloned()
+            .ok_or_else(|| app::Error::new(1).with_message("AWS did not return role credentials."))?;
+
+        let credentials_expire_at = chrono::Utc
+            .timestamp_millis_opt(role_credentials.expiration())
+            .single()
+            .map(|expiration| expiration.to_rfc3339())
+            .unwrap_or_default();
+
+        cache::write(&cache::Entry {
+            start_url,
+            region,
+            access_token,
+            expires_at,
+            client_id,
+            client_secret,
+            access_key_id: role_credentials.access_key_id().unwrap_or_default().to_owned(),
+            secret_access_key: role_credentials.secret_access_key().unwrap_or_default().to_owned(),
+            session_token: role_credentials.session_token().unwrap_or_default().to_owned(),
+            credentials_expire_at,
+        })?;
+
+        Ok(())
+    })
+}
+
+/// Polls the token endpoint until the user completes verification in their browser.
+async fn poll_for_token(
+    client: &ssooidc::Client,
+    client_id: &str,
+    client_secret: &str,
+    device_code: &str,
+    interval: Duration,
+) -> app::Result<ssooidc::operation::create_token::CreateTokenOutput> {
+    loop {
+        let result = client
+            .create_token()
+            .grant_type(DEVICE_GRANT_TYPE)
+            .client_id(client_id)
+            .client_secret(client_secret)
+            .device_code(device_code)
+            .send()
+            .await;
+
+        match result {
+            Ok(token) => return Ok(token),
+            Err(error) if is_authorization_pending(&error) => sleep(interval).await,
+            Err(error) => return Err(app::Error::new(1).with_message(error.to_string())),
+        }
+    }
+}
+
+/// Checks whether a `CreateToken` error indicates the user has not yet completed verification.
+fn is_authorization_pending<R>(
+    error: &ssooidc::error::SdkError<ssooidc::operation::create_token::CreateTokenError, R>,
+) -> bool {
+    matches!(
+        error.as_service_error(),
+        Some(ssooidc::operation::create_token::CreateTokenError::AuthorizationPendingException(_))
+    )
+}
+
+/// Reads a required setting from the profile, failing with a clear message if it is absent.
+fn required(profile: &Profile, key: &str) -> app::Result<String> {
+    profile
+        .get(key)
+        .filter(|value| !value.trim().is_empty())
+        .cloned()
+        .ok_or_else(|| app::Error::new(1).with_message(format!("`{}` is not set for this profile.", key)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_an_rfc3339_expiry() {
+        assert_eq!(
+            parse_expires_at("2030-01-01T00:00:00Z"),
+            Some(chrono::Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn falls_back_to_a_naive_utc_parse_for_a_timezone_less_expiry() {
+        assert_eq!(
+            parse_expires_at("2030-01-01T00:00:00"),
+            Some(chrono::Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn rejects_an_unparseable_expiry() {
+        assert_eq!(parse_expires_at("not a timestamp"), None);
+    }
+
+    #[test]
+    fn detects_an_authorization_pending_service_error() {
+        let error = ssooidc::error::SdkError::<ssooidc::operation::create_token::CreateTokenError, ()>::service_error(
+            ssooidc::operation::create_token::CreateTokenError::AuthorizationPendingException(
+                ssooidc::types::error::AuthorizationPendingException::builder().build(),
+            ),
+            (),
+        );
+
+        assert!(is_authorization_pending(&error));
+    }
+
+    #[test]
+    fn does_not_treat_other_service_errors_as_authorization_pending() {
+        let error = ssooidc::error::SdkError::<ssooidc::operation::create_token::CreateTokenError, ()>::service_error(
+            ssooidc::operation::create_token::CreateTokenError::InvalidGrantException(
+                ssooidc::types::error::InvalidGrantException::builder().build(),
+            ),
+            (),
+        );
+
+        assert!(!is_authorization_pending(&error));
+    }
+}
@@ -0,0 +1,92 @@
+//! Caches temporary credentials obtained by assuming an IAM role, keyed by profile and role ARN.
+
+use crate::app;
+use sha1::{Digest, Sha1};
+use std::{fs, path};
+
+/// A cached set of temporary credentials for a role.
+#[derive(Clone, Default)]
+pub struct Entry {
+    /// The temporary access key id.
+    pub access_key_id: String,
+
+    /// The temporary secret access key.
+    pub secret_access_key: String,
+
+    /// The temporary session token.
+    pub session_token: String,
+
+    /// The RFC 3339 timestamp the credentials expire at.
+    pub expiration: String,
+}
+
+/// Reads the cached credentials for a profile and role ARN, if any exist.
+pub fn read(profile: &str, role_arn: &str) -> app::Result<Option<Entry>> {
+    let path = cache_path(profile, role_arn);
+
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let contents = fs::read_to_string(path)?;
+
+    Ok(Some(Entry {
+        access_key_id: field(&contents, "accessKeyId").unwrap_or_default(),
+        secret_access_key: field(&contents, "secretAccessKey").unwrap_or_default(),
+        session_token: field(&contents, "sessionToken").unwrap_or_default(),
+        expiration: field(&contents, "expiration").unwrap_or_default(),
+    }))
+}
+
+/// Writes temporary credentials to the cache, replacing any existing entry for the same
+/// profile and role ARN.
+pub fn write(profile: &str, role_arn: &str, entry: &Entry) -> app::Result<()> {
+    let path = cache_path(profile, role_arn);
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let json = format!(
+        "{{\n  \
+           \"accessKeyId\": \"{}\",\n  \
+           \"secretAccessKey\": \"{}\",\n  \
+           \"sessionToken\": \"{}\",\n  \
+           \"expiration\": \"{}\"\n\
+         }}\n",
+        entry.access_key_id, entry.secret_access_key, entry.session_token, entry.expiration,
+    );
+
+    fs::write(path, json)?;
+
+    Ok(())
+}
+
+/// Extracts a top-level string field from a minimal JSON document.
+fn field(contents: &str, name: &str) -> Option<String> {
+    let needle = format!("\"{}\"", name);
+    let after_key = &contents[contents.find(&needle)? + needle.len()..];
+    let after_colon = &after_key[after_key.find(':')? + 1..].trim_start();
+    let after_quote = after_colon.strip_prefix('"')?;
+    let end = after_quote.find('"')?;
+
+    Some(after_quote[..end].to_owned())
+}
+
+/// Computes the path to the cache file for a given profile and role ARN, matching the layout
+/// the AWS CLI itself uses for its own assume-role credential cache.
+fn cache_path(profile: &str, role_arn: &str) -> path::PathBuf {
+    let digest = Sha1::digest(format!("{}:{}", profile, role_arn).as_bytes());
+    let name = digest.iter().map(|byte| format!("{:02x}", byte)).collect::<String>();
+
+    cache_dir().join(format!("{}.json", name))
+}
+
+/// Resolves the directory the AWS CLI shares for its assume-role credential cache.
+fn cache_dir() -> path::PathBuf {
+    home::home_dir()
+        .expect("The home directory could not be determined.")
+        .join(".aws")
+        .join("cli")
+        .join("cache")
+}
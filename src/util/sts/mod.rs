@@ -0,0 +1,169 @@
+//! Assumes IAM roles via STS, prompting for MFA when required, and caches the resulting
+//! temporary credentials.
+
+pub mod cache;
+
+use crate::app;
+use std::io::Write;
+use tokio::runtime::Runtime;
+
+/// A cached assumed-role session expiring within this many minutes is treated as already stale,
+/// so a fresh `AssumeRole` call has time to complete before the old credentials actually expire.
+const EXPIRY_MARGIN_MINUTES: i64 = 5;
+
+/// Temporary credentials resolved for an assumed role.
+pub struct Credentials {
+    /// The temporary access key id.
+    pub access_key_id: String,
+
+    /// The temporary secret access key.
+    pub secret_access_key: String,
+
+    /// The temporary session token.
+    pub session_token: String,
+}
+
+/// The options used to assume a role.
+pub struct AssumeRoleRequest<'a> {
+    /// The ARN of the role to assume.
+    pub role_arn: &'a str,
+
+    /// The ARN of the MFA device to authenticate with, if the role requires it.
+    pub mfa_serial: Option<&'a str>,
+
+    /// The current MFA token code, if already known.
+    pub token_code: Option<&'a str>,
+
+    /// How long the resulting credentials should remain valid for, in seconds.
+    pub duration_seconds: Option<i32>,
+}
+
+/// Assumes a role, reusing a cached session if one is still valid, prompting for an MFA code
+/// through the [`app::Context`] streams if the role requires one and none was given.
+pub fn assume_role(
+    context: &mut impl app::Context,
+    profile: &str,
+    request: AssumeRoleRequest,
+) -> app::Result<Credentials> {
+    if let Some(cached) = cache::read(profile, request.role_arn)? {
+        if is_fresh(&cached.expiration) {
+            return Ok(Credentials {
+                access_key_id: cached.access_key_id,
+                secret_access_key: cached.secret_access_key,
+                session_token: cached.session_token,
+            });
+        }
+    }
+
+    let token_code = match request.token_code {
+        Some(code) => Some(code.to_owned()),
+        None if request.mfa_serial.is_some() => Some(prompt_for_token_code(context)?),
+        None => None,
+    };
+
+    let region = context.region().map(str::to_owned);
+
+    Runtime::new()?.block_on(async {
+        let mut config_loader =
+            aws_config::defaults(aws_config::BehaviorVersion::latest()).profile_name(profile);
+
+        if let Some(region) = region {
+            config_loader = config_loader.region(aws_config::Region::new(region));
+        }
+
+        let shared_config = config_loader.load().await;
+        let sts = aws_sdk_sts::Client::new(&shared_config);
+
+        let mut call = sts
+            .assume_role()
+            .role_arn(request.role_arn)
+            .role_session_name(session_name());
+
+        if let Some(duration_seconds) = request.duration_seconds {
+            call = call.duration_seconds(duration_seconds);
+        }
+
+        if let Some(serial) = request.mfa_serial {
+            call = call.serial_number(serial);
+        }
+
+        if let Some(code) = &token_code {
+            call = call.token_code(code);
+        }
+
+        let output = call
+            .send()
+            .await
+            .map_err(|error| app::Error::new(1).with_message(error.to_string()))?;
+
+        let credentials = output
+            .credentials()
+            .ok_or_else(|| app::Error::new(1).with_message("AWS did not return temporary credentials."))?;
+
+        let entry = cache::Entry {
+            access_key_id: credentials.access_key_id().to_owned(),
+            secret_access_key: credentials.secret_access_key().to_owned(),
+            session_token: credentials.session_token().to_owned(),
+            expiration: credentials
+                .expiration()
+                .fmt(aws_smithy_types::date_time::Format::DateTime)
+                .unwrap_or_default(),
+        };
+
+        cache::write(profile, request.role_arn, &entry)?;
+
+        Ok(Credentials {
+            access_key_id: entry.access_key_id,
+            secret_access_key: entry.secret_access_key,
+            session_token: entry.session_token,
+        })
+    })
+}
+
+/// Returns the cached credentials for a profile and role ARN, without performing a new
+/// `AssumeRole` call or prompting for MFA.
+///
+/// `None` is returned if no session has been cached, or the cached one has gone stale.
+pub fn cached_credentials(profile: &str, role_arn: &str) -> app::Result<Option<cache::Entry>> {
+    let Some(entry) = cache::read(profile, role_arn)? else {
+        return Ok(None);
+    };
+
+    if !is_fresh(&entry.expiration) {
+        return Ok(None);
+    }
+
+    Ok(Some(entry))
+}
+
+/// Checks whether a cached expiration timestamp is still in the future, past a safety margin.
+fn is_fresh(expiration: &str) -> bool {
+    let Ok(expiration) = chrono::DateTime::parse_from_rfc3339(expiration) else {
+        return false;
+    };
+
+    let margin = chrono::Duration::minutes(EXPIRY_MARGIN_MINUTES);
+
+    expiration > chrono::Utc::now() + margin
+}
+
+/// Prompts the user for their current MFA token code through the context streams.
+fn prompt_for_token_code(context: &mut impl app::Context) -> app::Result<String> {
+    {
+        let mut output = context.output().lock().unwrap();
+
+        write!(output, "Enter MFA code: ")?;
+        output.flush()?;
+    }
+
+    let mut token_code = String::new();
+
+    std::io::stdin().read_line(&mut token_code)?;
+
+    Ok(token_code.trim().to_owned())
+}
+
+/// Builds a unique-enough role session name for the assumed role.
+fn session_name() -> String {
+    format!("aws-login-{}", std::process::id())
+}
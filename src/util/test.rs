@@ -0,0 +1,98 @@
+//! Test doubles shared by unit tests across the crate.
+
+use crate::app;
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+
+/// A [`Write`] implementation that appends to a shared, clonable buffer.
+#[derive(Clone, Default)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// An in-memory [`app::Context`] implementation used by unit tests.
+pub struct TestContext {
+    /// The profile that will be returned by the context.
+    profile: Option<String>,
+
+    /// The region that will be returned by the context.
+    region: Option<String>,
+
+    /// The buffer backing the output stream.
+    output: SharedBuffer,
+
+    /// The buffer backing the error stream.
+    error: SharedBuffer,
+
+    /// The stream handed out by [`app::Context::output`].
+    output_writer: Mutex<Box<dyn Write + Send>>,
+
+    /// The stream handed out by [`app::Context::error`].
+    error_writer: Mutex<Box<dyn Write + Send>>,
+}
+
+impl TestContext {
+    /// Sets the profile that will be returned by the context.
+    pub fn with_profile(mut self, profile: String) -> Self {
+        self.profile = Some(profile);
+        self
+    }
+
+    /// Sets the region that will be returned by the context.
+    pub fn with_region(mut self, region: String) -> Self {
+        self.region = Some(region);
+        self
+    }
+
+    /// Returns everything written to the output stream as a string.
+    pub fn output_as_string(&self) -> String {
+        String::from_utf8_lossy(&self.output.0.lock().unwrap()).into_owned()
+    }
+
+    /// Returns everything written to the error stream as a string.
+    pub fn error_as_string(&self) -> String {
+        String::from_utf8_lossy(&self.error.0.lock().unwrap()).into_owned()
+    }
+}
+
+impl Default for TestContext {
+    fn default() -> Self {
+        let output = SharedBuffer::default();
+        let error = SharedBuffer::default();
+
+        Self {
+            profile: None,
+            region: None,
+            output_writer: Mutex::new(Box::new(output.clone())),
+            error_writer: Mutex::new(Box::new(error.clone())),
+            output,
+            error,
+        }
+    }
+}
+
+impl app::Context for TestContext {
+    fn profile(&self) -> Option<&str> {
+        self.profile.as_deref()
+    }
+
+    fn region(&self) -> Option<&str> {
+        self.region.as_deref()
+    }
+
+    fn output(&self) -> &Mutex<Box<dyn Write + Send>> {
+        &self.output_writer
+    }
+
+    fn error(&self) -> &Mutex<Box<dyn Write + Send>> {
+        &self.error_writer
+    }
+}